@@ -0,0 +1,249 @@
+//! Derive macro for the [`Peel`] trait.
+//!
+//! This crate is an implementation detail of `peel-off`; use it through the
+//! `derive` feature of that crate rather than depending on it directly.
+//!
+//! Applying `#[derive(Peel)]` to an enum with exactly one `#[peel]` variant
+//! generates the residual enum (the non-peeled variants, verbatim) together
+//! with the `Peel` impl that splits the two apart.
+//!
+//! [`Peel`]: ../peel_off/trait.Peel.html
+
+use proc_macro::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use quote::ToTokens;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Variant;
+
+/// Derive a [`Peel`] impl for an enum.
+///
+/// Exactly one variant must be marked `#[peel]`. Its payload becomes the
+/// associated `Peeled` type; every other variant is copied verbatim into a
+/// freshly-named `<Name>Residual` enum that becomes the associated `Residual`
+/// type. Generics and where-clauses are forwarded to the impl in full, and to
+/// the generated enum restricted to the parameters its variants actually use.
+#[proc_macro_derive(Peel, attributes(peel))]
+pub fn derive_peel(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`Peel` can only be derived for enums",
+            ))
+        }
+    };
+
+    let peeled = find_peeled(data)?;
+    let residual_decl: Vec<&Variant> =
+        data.variants.iter().filter(|v| !is_peeled(v)).collect();
+
+    let name = &input.ident;
+    let residual_name = format_ident!("{}Residual", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The residual enum only mentions the non-peeled variants, so forwarding
+    // every input generic would leave `T` unused (`error[E0392]`) whenever a
+    // parameter is exclusive to the `#[peel]` variant. Keep only the generics
+    // the residual variants actually reference.
+    let residual_generics = retain_used_generics(&input.generics, &residual_decl);
+    let (res_impl_generics, res_ty_generics, res_where_clause) =
+        residual_generics.split_for_impl();
+
+    let peeled_ty = payload_type(peeled)?;
+
+    let match_arms = data.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let bindings = field_bindings(&v.fields);
+        if is_peeled(v) {
+            let payload = payload_value(&v.fields);
+            quote! { #name::#ident #bindings => ::core::result::Result::Err(#payload), }
+        } else {
+            quote! {
+                #name::#ident #bindings => ::core::result::Result::Ok(
+                    #residual_name::#ident #bindings
+                ),
+            }
+        }
+    });
+
+    let vis = &input.vis;
+
+    Ok(quote! {
+        #vis enum #residual_name #res_impl_generics #res_where_clause {
+            #(#residual_decl),*
+        }
+
+        impl #impl_generics ::peel_off::Peel for #name #ty_generics #where_clause {
+            type Peeled = #peeled_ty;
+            type Residual = #residual_name #res_ty_generics;
+
+            fn peel(self) -> ::core::result::Result<Self::Residual, Self::Peeled> {
+                match self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    })
+}
+
+fn is_peeled(v: &Variant) -> bool {
+    v.attrs.iter().any(|a| a.path().is_ident("peel"))
+}
+
+/// Build a `Generics` holding only the parameters (and where-predicates) that
+/// the residual variants reference, so the generated residual enum declares no
+/// unused type parameters.
+fn retain_used_generics(generics: &syn::Generics, variants: &[&Variant]) -> syn::Generics {
+    let mut used = std::collections::HashSet::new();
+    for v in variants {
+        for field in v.fields.iter() {
+            collect_idents(field.ty.to_token_stream().into_iter(), &mut used);
+        }
+    }
+
+    let param_name = |p: &syn::GenericParam| match p {
+        syn::GenericParam::Type(t) => t.ident.to_string(),
+        syn::GenericParam::Const(c) => c.ident.to_string(),
+        syn::GenericParam::Lifetime(l) => l.lifetime.ident.to_string(),
+    };
+
+    let mut out = generics.clone();
+    out.params = generics
+        .params
+        .iter()
+        .filter(|p| used.contains(&param_name(p)))
+        .cloned()
+        .collect();
+
+    // Drop where-predicates that mention a parameter we removed; keeping them
+    // would reintroduce a reference to an undeclared generic.
+    let dropped: std::collections::HashSet<String> = generics
+        .params
+        .iter()
+        .map(param_name)
+        .filter(|n| !out.params.iter().any(|p| &param_name(p) == n))
+        .collect();
+    if let Some(where_clause) = out.where_clause.as_mut() {
+        let kept = where_clause
+            .predicates
+            .iter()
+            .filter(|pred| {
+                let mut idents = std::collections::HashSet::new();
+                collect_idents(pred.to_token_stream().into_iter(), &mut idents);
+                idents.is_disjoint(&dropped)
+            })
+            .cloned()
+            .collect();
+        where_clause.predicates = kept;
+        if where_clause.predicates.is_empty() {
+            out.where_clause = None;
+        }
+    }
+
+    out
+}
+
+/// Collect every identifier (including lifetime names) appearing in a token
+/// stream, recursing into delimited groups.
+fn collect_idents(
+    tokens: proc_macro2::token_stream::IntoIter,
+    acc: &mut std::collections::HashSet<String>,
+) {
+    for tt in tokens {
+        match tt {
+            proc_macro2::TokenTree::Ident(i) => {
+                acc.insert(i.to_string());
+            }
+            proc_macro2::TokenTree::Group(g) => collect_idents(g.stream().into_iter(), acc),
+            _ => {}
+        }
+    }
+}
+
+fn find_peeled(data: &syn::DataEnum) -> syn::Result<&Variant> {
+    let mut marked = data.variants.iter().filter(|v| is_peeled(v));
+    let first = marked.next().ok_or_else(|| {
+        syn::Error::new(
+            data.variants.span(),
+            "`#[derive(Peel)]` requires exactly one `#[peel]` variant, found none",
+        )
+    })?;
+    if let Some(extra) = marked.next() {
+        return Err(syn::Error::new(
+            extra.span(),
+            "`#[derive(Peel)]` allows at most one `#[peel]` variant",
+        ));
+    }
+    Ok(first)
+}
+
+/// The associated `Peeled` type for the marked variant: the field type for a
+/// single-field variant, a tuple of the field types otherwise, and the unit
+/// type for a fieldless variant.
+fn payload_type(v: &Variant) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match &v.fields {
+        Fields::Unit => quote! { () },
+        Fields::Unnamed(f) if f.unnamed.len() == 1 => {
+            let ty = &f.unnamed[0].ty;
+            quote! { #ty }
+        }
+        Fields::Unnamed(f) => {
+            let tys = f.unnamed.iter().map(|f| &f.ty);
+            quote! { ( #(#tys),* ) }
+        }
+        Fields::Named(f) if f.named.len() == 1 => {
+            let ty = &f.named[0].ty;
+            quote! { #ty }
+        }
+        Fields::Named(f) => {
+            let tys = f.named.iter().map(|f| &f.ty);
+            quote! { ( #(#tys),* ) }
+        }
+    })
+}
+
+/// Binding pattern for a variant, e.g. `(f0, f1)` or `{ a, b }`.
+fn field_bindings(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(f) => {
+            let names = (0..f.unnamed.len()).map(|i| format_ident!("f{}", i));
+            quote! { ( #(#names),* ) }
+        }
+        Fields::Named(f) => {
+            let names = f.named.iter().map(|f| f.ident.as_ref().unwrap());
+            quote! { { #(#names),* } }
+        }
+    }
+}
+
+/// The value handed to `Err` for the peeled variant, mirroring [`payload_type`].
+fn payload_value(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Unit => quote! { () },
+        Fields::Unnamed(f) if f.unnamed.len() == 1 => quote! { f0 },
+        Fields::Unnamed(f) => {
+            let names = (0..f.unnamed.len()).map(|i| format_ident!("f{}", i));
+            quote! { ( #(#names),* ) }
+        }
+        Fields::Named(f) if f.named.len() == 1 => {
+            let name = f.named[0].ident.as_ref().unwrap();
+            quote! { #name }
+        }
+        Fields::Named(f) => {
+            let names = f.named.iter().map(|f| f.ident.as_ref().unwrap());
+            quote! { ( #(#names),* ) }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use core::fmt;
+
+/// A two-sided value, used as the accumulator when [`peel_chain`] collapses a
+/// ladder of nested peels into a single [`Peeled`].
+///
+/// `Left` carries the payload peeled at the outer level, `Right` the payload
+/// peeled from the residual one level in.
+///
+/// [`peel_chain`]: crate::Peeled::peel_chain
+/// [`Peeled`]: crate::Peeled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for Either<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Either::Left(l) => l.fmt(f),
+            Either::Right(r) => r.fmt(f),
+        }
+    }
+}
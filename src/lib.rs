@@ -1,8 +1,27 @@
 #![no_std]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2, try_trait_v2_residual))]
 #![doc = include_str!("../README.md")]
 
+// Lets the `#[derive(Peel)]` output refer to the crate by its public name
+// (`::peel_off::Peel`) even from within the crate's own tests.
+extern crate self as peel_off;
+
+#[macro_use]
+mod macros;
+
+mod either;
 mod peel;
 mod peeled;
 
+pub use either::Either;
 pub use peel::Peel;
+pub use peel::PeelInto;
 pub use peeled::Peeled;
+pub use peeled::Sourced;
+
+/// Derive macro for [`Peel`], available with the `derive` feature.
+///
+/// Re-exported so `use peel_off::Peel;` brings both the trait and its derive
+/// into scope, the same way `serde` re-exports its derives.
+#[cfg(feature = "derive")]
+pub use peel_derive::Peel;
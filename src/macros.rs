@@ -0,0 +1,94 @@
+/// Define an enum together with a [`Peel`] impl that pulls several marked
+/// variants into one peeled enum and leaves the rest as a single residual enum.
+///
+/// Where `#[derive(Peel)]` peels exactly one variant, `peel_all!` peels a whole
+/// set in a single step, so a boundary handling both `Timeout` and `RateLimited`
+/// gets `Peeled = TimeoutOrRateLimited` from one `peel()` call instead of a
+/// nested ladder.
+///
+/// ```
+/// peel_off::peel_all! {
+///     enum MyError {
+///         peeled TimeoutOrRateLimited { Timeout(u64), RateLimited(u64) }
+///         residual MyErrorResidual { NotFound(u32), Internal(u32) }
+///     }
+/// }
+/// ```
+///
+/// Unlike `#[derive(Peel)]`, `peel_all!` only accepts single-field tuple
+/// variants (`Variant(Type)`); named-field and multi-field variants are not
+/// supported here.
+///
+/// [`Peel`]: crate::Peel
+#[macro_export]
+macro_rules! peel_all {
+    (
+        $(#[$emeta:meta])*
+        $evis:vis enum $name:ident {
+            peeled $pname:ident { $($pv:ident($pty:ty)),* $(,)? }
+            residual $rname:ident { $($rv:ident($rty:ty)),* $(,)? }
+        }
+    ) => {
+        $(#[$emeta])*
+        #[allow(dead_code)]
+        $evis enum $name {
+            $($pv($pty),)*
+            $($rv($rty),)*
+        }
+
+        #[allow(dead_code)]
+        $evis enum $pname {
+            $($pv($pty),)*
+        }
+
+        #[allow(dead_code)]
+        $evis enum $rname {
+            $($rv($rty),)*
+        }
+
+        impl $crate::Peel for $name {
+            type Peeled = $pname;
+            type Residual = $rname;
+
+            fn peel(self) -> ::core::result::Result<$rname, $pname> {
+                match self {
+                    $( $name::$pv(x) => ::core::result::Result::Err($pname::$pv(x)), )*
+                    $( $name::$rv(x) => ::core::result::Result::Ok($rname::$rv(x)), )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use crate::Peel;
+
+    peel_all! {
+        enum MyError {
+            peeled Handled { Timeout(u64), RateLimited(u64) }
+            residual MyErrorResidual { NotFound(String), Internal(String) }
+        }
+    }
+
+    #[test]
+    fn test_peel_all_peeled() {
+        assert!(matches!(
+            MyError::RateLimited(3).peel(),
+            Err(Handled::RateLimited(3))
+        ));
+    }
+
+    #[test]
+    fn test_peel_all_residual() {
+        assert!(matches!(
+            MyError::NotFound("k".to_string()).peel(),
+            Ok(MyErrorResidual::NotFound(s)) if s == "k"
+        ));
+    }
+}
@@ -9,6 +9,18 @@ pub trait Peel {
     type Peeled;
     type Residual;
     fn peel(self) -> Result<Self::Residual, Self::Peeled>;
+
+    /// Peel into a [`Peeled`] value instead of a `Result`, so the combinators
+    /// on `Peeled` (`map_peeled`, `map_residual`, ...) can be chained directly.
+    fn into_peeled(self) -> crate::Peeled<Self::Peeled, Self::Residual>
+    where
+        Self: Sized,
+    {
+        match self.peel() {
+            Ok(r) => crate::Peeled::Residual(r),
+            Err(p) => crate::Peeled::Peeled(p),
+        }
+    }
 }
 
 /// Blanket impl: peeling a `Result<T, E>` where `E: Peel`
@@ -31,6 +43,39 @@ impl<T, E: Peel> Peel for Result<T, E> {
     }
 }
 
+/// Peel a `Result<T, E>` at a function boundary, converting the residual into
+/// the caller's error type in the same expression.
+///
+/// This bridges `Peel` with Rust's `?`-driven `From` conversion: the peeled
+/// payload propagates untouched while the residual is turned into `O` via
+/// `Into`, so a handler can write `let r = res.peel_into::<OuterErr>()?;` and
+/// deal only with `Result<T, OuterErr>`.
+///
+/// The `PInner` type parameter names the inner residual that actually gets
+/// converted (`E::Residual` for `Result<T, E>`). `Self::Residual` here is
+/// `Result<T, E::Residual>`, so the bound must reference `PInner` directly
+/// rather than `Self::Residual`.
+pub trait PeelInto<T, PInner>: Peel {
+    /// Split off the peeled payload (as the `Err`) and, for everything else,
+    /// convert the residual into `O`.
+    fn peel_into<O>(self) -> Result<Result<T, O>, Self::Peeled>
+    where
+        PInner: Into<O>;
+}
+
+impl<T, E: Peel> PeelInto<T, E::Residual> for Result<T, E> {
+    fn peel_into<O>(self) -> Result<Result<T, O>, E::Peeled>
+    where
+        E::Residual: Into<O>,
+    {
+        match self.peel() {
+            Ok(Ok(v)) => Ok(Ok(v)),
+            Ok(Err(residual)) => Ok(Err(residual.into())),
+            Err(peeled) => Err(peeled),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -112,4 +157,70 @@ mod tests {
         assert_eq!(handle(Err(MyError::Timeout(300))), Err(300));
         assert_eq!(handle(Err(MyError::NotFound("x".to_string()))), Ok(-1));
     }
+
+    #[derive(Debug, PartialEq)]
+    enum OuterErr {
+        Peel(ResidualError),
+    }
+
+    impl From<ResidualError> for OuterErr {
+        fn from(r: ResidualError) -> Self {
+            OuterErr::Peel(r)
+        }
+    }
+
+    #[test]
+    fn test_peel_into() {
+        let ok: Result<i32, MyError> = Ok(1);
+        assert_eq!(ok.peel_into::<OuterErr>(), Ok(Ok(1)));
+
+        let residual: Result<i32, MyError> = Err(MyError::Internal("b".to_string()));
+        assert_eq!(
+            residual.peel_into::<OuterErr>(),
+            Ok(Err(OuterErr::Peel(ResidualError::Internal("b".to_string()))))
+        );
+
+        let peeled: Result<i32, MyError> = Err(MyError::Timeout(9));
+        assert_eq!(peeled.peel_into::<OuterErr>(), Err(9));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derived_peel() {
+        use crate::Peel as _;
+
+        #[derive(crate::Peel)]
+        enum DerivedError {
+            #[peel]
+            Timeout(u64),
+            NotFound(String),
+        }
+
+        assert!(matches!(DerivedError::Timeout(7).peel(), Err(7)));
+        assert!(matches!(
+            DerivedError::NotFound("k".to_string()).peel(),
+            Ok(DerivedErrorResidual::NotFound(s)) if s == "k"
+        ));
+    }
+
+    /// The type parameter is used only by the `#[peel]` variant, so the
+    /// residual enum must not carry it (would be `error[E0392]` otherwise).
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derived_peel_generic() {
+        use crate::Peel as _;
+
+        #[derive(crate::Peel)]
+        enum Carrier<T> {
+            #[peel]
+            Payload(T),
+            NotFound(String),
+        }
+
+        assert!(matches!(Carrier::<u32>::Payload(7).peel(), Err(7)));
+        assert!(matches!(
+            Carrier::<u32>::NotFound("k".to_string()).peel(),
+            Ok(CarrierResidual::NotFound(s)) if s == "k"
+        ));
+    }
 }
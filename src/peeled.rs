@@ -1,6 +1,7 @@
 use core::error::Error;
 use core::fmt;
 
+use crate::Either;
 use crate::Peel;
 
 /// The result of peeling an enum: either the residual (non-peeled) variants,
@@ -11,6 +12,115 @@ pub enum Peeled<P, R> {
     Residual(R),
 }
 
+/// Residual wrapper produced by [`Peeled::from_source`].
+///
+/// It Displays exactly like the inner error but reports that inner value as its
+/// [`Error::source`], so the `from_source` lineage is spliced in *without*
+/// broadening `source()` for every plain [`Peeled`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sourced<E>(pub E);
+
+impl<E: fmt::Display> fmt::Display for Sourced<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E: Error + 'static> Error for Sourced<E> {
+    /// Report the wrapped inner error as the next node in the chain, even when
+    /// it is a leaf error with no `source()` of its own.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl<P, I> Peeled<P, Sourced<I>> {
+    /// Wrap an inner error as the residual, recording it as the reported
+    /// [`Error::source`] via the [`Sourced`] wrapper: walking the chain visits
+    /// `inner` itself (and then `inner`'s own `source()`), so tools see the full
+    /// lineage even when `inner` is a leaf error with no `source()`.
+    pub fn from_source(inner: I) -> Self {
+        Peeled::Residual(Sourced(inner))
+    }
+}
+
+impl<P, R> Peeled<P, R> {
+    /// Transform the peeled payload, leaving the residual untouched.
+    pub fn map_peeled<Q>(self, f: impl FnOnce(P) -> Q) -> Peeled<Q, R> {
+        match self {
+            Peeled::Peeled(p) => Peeled::Peeled(f(p)),
+            Peeled::Residual(r) => Peeled::Residual(r),
+        }
+    }
+
+    /// Transform the residual, leaving the peeled payload untouched.
+    pub fn map_residual<S>(self, f: impl FnOnce(R) -> S) -> Peeled<P, S> {
+        match self {
+            Peeled::Peeled(p) => Peeled::Peeled(p),
+            Peeled::Residual(r) => Peeled::Residual(f(r)),
+        }
+    }
+
+    /// The peeled payload, if this is the peeled variant (analogous to [`Result::err`]).
+    pub fn peeled(self) -> Option<P> {
+        match self {
+            Peeled::Peeled(p) => Some(p),
+            Peeled::Residual(_) => None,
+        }
+    }
+
+    /// The residual, if this is a residual variant (analogous to [`Result::ok`]).
+    pub fn residual(self) -> Option<R> {
+        match self {
+            Peeled::Residual(r) => Some(r),
+            Peeled::Peeled(_) => None,
+        }
+    }
+
+    /// `true` if this is the peeled variant.
+    pub fn is_peeled(&self) -> bool {
+        matches!(self, Peeled::Peeled(_))
+    }
+
+    /// `true` if this is a residual variant.
+    pub fn is_residual(&self) -> bool {
+        matches!(self, Peeled::Residual(_))
+    }
+
+    /// The residual, panicking if this is the peeled variant.
+    pub fn unwrap_residual(self) -> R
+    where
+        P: fmt::Debug,
+    {
+        match self {
+            Peeled::Residual(r) => r,
+            Peeled::Peeled(p) => panic!("called `unwrap_residual()` on a `Peeled` value: {p:?}"),
+        }
+    }
+}
+
+impl<P, R> Peeled<P, R>
+where
+    R: Peel,
+{
+    /// Peel one more variant out of the residual, collapsing the would-be
+    /// nested `Peeled<P, Peeled<..>>` into a single flat ladder.
+    ///
+    /// The payload peeled at this level lands in [`Either::Left`], the one
+    /// peeled from the residual in [`Either::Right`]; the remaining residual is
+    /// whatever `R::peel()` leaves behind. Repeated calls accumulate each
+    /// handled variant into the `Either` chain rather than deepening the type.
+    pub fn peel_chain(self) -> Peeled<Either<P, R::Peeled>, R::Residual> {
+        match self {
+            Peeled::Peeled(p) => Peeled::Peeled(Either::Left(p)),
+            Peeled::Residual(r) => match r.peel() {
+                Ok(residual) => Peeled::Residual(residual),
+                Err(peeled) => Peeled::Peeled(Either::Right(peeled)),
+            },
+        }
+    }
+}
+
 impl<P, R> Peel for Peeled<P, R> {
     type Peeled = P;
     type Residual = R;
@@ -34,9 +144,14 @@ impl<P: fmt::Display, R: fmt::Display> fmt::Display for Peeled<P, R> {
 
 impl<P, R> Error for Peeled<P, R>
 where
-    P: Error,
-    R: Error,
+    P: Error + 'static,
+    R: Error + 'static,
 {
+    /// Forward transparently to the contained value's own `source()`. Because
+    /// `Peeled`'s `Display` already delegates to that value, re-reporting the
+    /// value here would add a chain node that Displays identically to the
+    /// `Peeled` itself; splicing an extra node is instead opt-in via
+    /// [`Peeled::from_source`] (see [`Sourced`]).
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Peeled::Residual(r) => r.source(),
@@ -45,6 +160,64 @@ where
     }
 }
 
+/// `?` support for `Peeled` itself, gated behind the `try_trait` feature.
+///
+/// With this, `let r = some_peeled?;` yields the residual `R` directly and
+/// short-circuits the function with the peeled payload `P`, mirroring how
+/// `Result<R, P>` behaves but without the intermediate `.peel()` call.
+#[cfg(feature = "try_trait")]
+mod try_trait {
+    use core::convert::Infallible;
+    use core::ops::ControlFlow;
+    use core::ops::FromResidual;
+    use core::ops::Residual;
+    use core::ops::Try;
+
+    use super::Peeled;
+
+    impl<P, R> Try for Peeled<P, R> {
+        type Output = R;
+        type Residual = Peeled<P, Infallible>;
+
+        fn from_output(output: Self::Output) -> Self {
+            Peeled::Residual(output)
+        }
+
+        // `Self::Residual` would be ambiguous between the `Try` and `Peel`
+        // associated types, so name the `Try` one explicitly.
+        fn branch(self) -> ControlFlow<<Self as Try>::Residual, Self::Output> {
+            match self {
+                Peeled::Residual(r) => ControlFlow::Continue(r),
+                Peeled::Peeled(p) => ControlFlow::Break(Peeled::Peeled(p)),
+            }
+        }
+    }
+
+    // Satisfies `Try::Residual: Residual<Output>`: the residual can be turned
+    // back into a `Peeled` with any output type, rebuilding the peeled payload.
+    impl<P, O> Residual<O> for Peeled<P, Infallible> {
+        type TryType = Peeled<P, O>;
+    }
+
+    impl<P, R> FromResidual<Peeled<P, Infallible>> for Peeled<P, R> {
+        fn from_residual(residual: Peeled<P, Infallible>) -> Self {
+            match residual {
+                Peeled::Peeled(p) => Peeled::Peeled(p),
+                Peeled::Residual(inf) => match inf {},
+            }
+        }
+    }
+
+    impl<T, P> FromResidual<Peeled<P, Infallible>> for Result<T, P> {
+        fn from_residual(residual: Peeled<P, Infallible>) -> Self {
+            match residual {
+                Peeled::Peeled(p) => Err(p),
+                Peeled::Residual(inf) => match inf {},
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -54,6 +227,8 @@ mod tests {
     use alloc::string::ToString;
     use core::fmt;
 
+    use crate::Either;
+
     use super::*;
 
     #[derive(Debug, Clone, PartialEq)]
@@ -77,6 +252,23 @@ mod tests {
     impl Error for Timeout {}
     impl Error for NotFound {}
 
+    #[derive(Debug)]
+    struct Wrapped {
+        src: Timeout,
+    }
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "wrapped")
+        }
+    }
+
+    impl Error for Wrapped {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.src)
+        }
+    }
+
     #[test]
     fn test_peeled_peel_residual() {
         let p: Peeled<Timeout, NotFound> = Peeled::Residual(NotFound("key".to_string()));
@@ -100,7 +292,68 @@ mod tests {
 
     #[test]
     fn test_peeled_error_source() {
-        let p: Peeled<Timeout, NotFound> = Peeled::Residual(NotFound("x".to_string()));
-        assert!(p.source().is_none());
+        // A plain `Peeled` is transparent: it forwards to the contained value's
+        // own `source()` instead of re-reporting a node that Displays the same.
+        let leaf: Peeled<Timeout, NotFound> = Peeled::Residual(NotFound("x".to_string()));
+        assert!(leaf.source().is_none());
+
+        let p: Peeled<NotFound, Wrapped> = Peeled::Residual(Wrapped { src: Timeout(7) });
+        let src = p.source().expect("forwards to the contained error's source");
+        assert_eq!(format!("{src}"), "timeout: 7ms");
+    }
+
+    #[test]
+    fn test_peeled_combinators() {
+        let off: Peeled<Timeout, NotFound> = Peeled::Peeled(Timeout(500));
+        assert!(off.is_peeled());
+        assert_eq!(off.map_peeled(|t| t.0).peeled(), Some(500));
+
+        let rest: Peeled<Timeout, NotFound> = Peeled::Residual(NotFound("key".to_string()));
+        assert!(rest.is_residual());
+        assert_eq!(rest.map_residual(|n| n.0).unwrap_residual(), "key".to_string());
+    }
+
+    #[test]
+    fn test_from_source_chain() {
+        // A leaf inner error (no `source()` of its own) must still appear as a
+        // node in the chain — the whole point of `from_source`.
+        let leaf: Peeled<Timeout, Sourced<NotFound>> =
+            Peeled::from_source(NotFound("k".to_string()));
+        let src = leaf.source().expect("inner error is recorded as the source");
+        assert_eq!(format!("{src}"), "not found: k");
+        assert!(src.source().is_none());
+
+        // An inner error that itself carries a source extends the chain further.
+        let p: Peeled<NotFound, Sourced<Wrapped>> =
+            Peeled::from_source(Wrapped { src: Timeout(5) });
+        let inner = p.source().expect("inner error is recorded as the source");
+        assert_eq!(format!("{inner}"), "wrapped");
+        assert_eq!(format!("{}", inner.source().expect("chain continues")), "timeout: 5ms");
+    }
+
+    #[test]
+    fn test_peel_chain() {
+        let nested: Peeled<Timeout, Peeled<NotFound, Timeout>> =
+            Peeled::Residual(Peeled::Peeled(NotFound("k".to_string())));
+        let flat = nested.peel_chain();
+        assert!(matches!(flat, Peeled::Peeled(Either::Right(NotFound(_)))));
+
+        let outer: Peeled<Timeout, Peeled<NotFound, Timeout>> = Peeled::Peeled(Timeout(5));
+        assert!(matches!(outer.peel_chain(), Peeled::Peeled(Either::Left(Timeout(5)))));
+    }
+
+    #[cfg(feature = "try_trait")]
+    #[test]
+    fn test_peeled_question_mark() {
+        fn handle(p: Peeled<Timeout, NotFound>) -> Result<String, Timeout> {
+            let residual = p?;
+            Ok(format!("{residual}"))
+        }
+
+        assert_eq!(
+            handle(Peeled::Residual(NotFound("key".to_string()))),
+            Ok("not found: key".to_string())
+        );
+        assert_eq!(handle(Peeled::Peeled(Timeout(500))), Err(Timeout(500)));
     }
 }